@@ -0,0 +1,78 @@
+use super::sweep_event::{EventQueue, SweepEvent};
+use geo::Coordinate;
+use num_traits::Float;
+use std::cmp::Reverse;
+use std::rc::Rc;
+
+/// Splits the segment `se`/`other` at `p`, pushing the two new half-segment events onto `queue`.
+///
+/// `z` is the interpolated value (if any) that should be carried by the new vertex at `p`;
+/// it is applied to both of the freshly created events, since they share the same point.
+pub fn divide_segment<F>(se: &Rc<SweepEvent<F>>, p: Coordinate<F>, queue: &mut EventQueue<F>, z: Option<F>)
+where
+    F: Float,
+{
+    let other = se.get_other_event().expect("segment has no other event");
+
+    let r = SweepEvent::new(
+        se.contour_id,
+        p,
+        false,
+        Rc::downgrade(se),
+        se.is_subject,
+        se.is_exterior_ring(),
+    );
+    let l = SweepEvent::new(
+        other.contour_id,
+        p,
+        true,
+        Rc::downgrade(&other),
+        se.is_subject,
+        se.is_exterior_ring(),
+    );
+
+    if let Some(z) = z {
+        r.set_z(z);
+        l.set_z(z);
+    }
+
+    se.set_other_event(&r);
+    other.set_other_event(&l);
+
+    queue.push(Reverse(r));
+    queue.push(Reverse(l));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::rc::Weak;
+
+    #[test]
+    fn z_is_carried_onto_both_synthesized_events() {
+        let other = SweepEvent::new(0, Coordinate { x: 10.0, y: 0.0 }, false, Weak::new(), true, true);
+        let se = SweepEvent::new(0, Coordinate { x: 0.0, y: 0.0 }, true, Rc::downgrade(&other), true, true);
+        se.set_other_event(&other);
+
+        let mut queue: EventQueue<f64> = EventQueue::new();
+        divide_segment(&se, Coordinate { x: 4.0, y: 0.0 }, &mut queue, Some(14.0));
+
+        // `se`'s other event is now the new right-half event at the split point, and `other`'s
+        // other event is now the new left-half event there; both should carry the interpolated Z.
+        assert_eq!(se.get_other_event().unwrap().get_z(), Some(14.0));
+        assert_eq!(other.get_other_event().unwrap().get_z(), Some(14.0));
+    }
+
+    #[test]
+    fn no_z_is_set_when_none_is_given() {
+        let other = SweepEvent::new(0, Coordinate { x: 10.0, y: 0.0 }, false, Weak::new(), true, true);
+        let se = SweepEvent::new(0, Coordinate { x: 0.0, y: 0.0 }, true, Rc::downgrade(&other), true, true);
+        se.set_other_event(&other);
+
+        let mut queue: EventQueue<f64> = EventQueue::new();
+        divide_segment(&se, Coordinate { x: 4.0, y: 0.0 }, &mut queue, None);
+
+        assert_eq!(se.get_other_event().unwrap().get_z(), None);
+        assert_eq!(other.get_other_event().unwrap().get_z(), None);
+    }
+}
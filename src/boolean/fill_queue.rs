@@ -0,0 +1,283 @@
+use super::sweep_event::{EventQueue, SweepEvent};
+use super::Operation;
+use geo::{Coordinate, LineString, Polygon, Rect};
+use num_traits::Float;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::rc::{Rc, Weak};
+
+fn point_less<F: Float>(a: Coordinate<F>, b: Coordinate<F>) -> bool {
+    if a.x != b.x {
+        a.x < b.x
+    } else {
+        a.y < b.y
+    }
+}
+
+fn process_ring<F>(
+    contour_id: usize,
+    ring: &LineString<F>,
+    is_subject: bool,
+    is_exterior_ring: bool,
+    bbox: &mut Rect<F>,
+    queue: &mut EventQueue<F>,
+) where
+    F: Float,
+{
+    // Degenerate (zero-length) edges are dropped, so the edge index used to tell ring-adjacent
+    // segments apart later (in `intersections::self_intersections`) has to be assigned after
+    // filtering rather than from the raw window position.
+    let edges: Vec<(Coordinate<F>, Coordinate<F>)> = ring
+        .0
+        .windows(2)
+        .map(|w| (w[0], w[1]))
+        .filter(|(p1, p2)| p1 != p2)
+        .collect();
+    let ring_edge_count = edges.len();
+
+    for (edge_index, (p1, p2)) in edges.into_iter().enumerate() {
+        bbox.min.x = bbox.min.x.min(p1.x.min(p2.x));
+        bbox.min.y = bbox.min.y.min(p1.y.min(p2.y));
+        bbox.max.x = bbox.max.x.max(p1.x.max(p2.x));
+        bbox.max.y = bbox.max.y.max(p1.y.max(p2.y));
+
+        let (left_point, right_point) = if point_less(p1, p2) { (p1, p2) } else { (p2, p1) };
+
+        let left_event = SweepEvent::new(contour_id, left_point, true, Weak::new(), is_subject, is_exterior_ring);
+        let right_event = SweepEvent::new(
+            contour_id,
+            right_point,
+            false,
+            Rc::downgrade(&left_event),
+            is_subject,
+            is_exterior_ring,
+        );
+        left_event.set_other_event(&right_event);
+        left_event.set_edge_index(edge_index, ring_edge_count);
+        right_event.set_edge_index(edge_index, ring_edge_count);
+
+        queue.push(Reverse(left_event));
+        queue.push(Reverse(right_event));
+    }
+}
+
+fn process_polygon<F>(
+    contour_id: &mut usize,
+    polygon: &Polygon<F>,
+    is_subject: bool,
+    bbox: &mut Rect<F>,
+    queue: &mut EventQueue<F>,
+) where
+    F: Float,
+{
+    *contour_id += 1;
+    process_ring(*contour_id, &polygon.exterior, is_subject, true, bbox, queue);
+
+    for hole in &polygon.interiors {
+        *contour_id += 1;
+        process_ring(*contour_id, hole, is_subject, false, bbox, queue);
+    }
+}
+
+/// Builds the initial sweep-event queue: every edge of every ring of `subject` and `clipping`
+/// becomes a pair of `SweepEvent`s (one per endpoint), and `sbbox`/`cbbox` are grown to the
+/// bounding box of each polygon set as a side effect.
+///
+/// `operation` is accepted but currently unused here; it is part of the signature for callers
+/// that already have it on hand (every caller is about to run a sweep for a specific
+/// `Operation`), not because this function does anything with it yet.
+pub fn fill_queue<F>(
+    subject: &[Polygon<F>],
+    clipping: &[Polygon<F>],
+    sbbox: &mut Rect<F>,
+    cbbox: &mut Rect<F>,
+    _operation: Operation,
+) -> EventQueue<F>
+where
+    F: Float,
+{
+    let mut queue = BinaryHeap::new();
+    let mut contour_id = 0;
+
+    for polygon in subject {
+        process_polygon(&mut contour_id, polygon, true, sbbox, &mut queue);
+    }
+    for polygon in clipping {
+        process_polygon(&mut contour_id, polygon, false, cbbox, &mut queue);
+    }
+
+    queue
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn empty_bbox() -> Rect<f64> {
+        Rect {
+            min: Coordinate {
+                x: f64::infinity(),
+                y: f64::infinity(),
+            },
+            max: Coordinate {
+                x: f64::neg_infinity(),
+                y: f64::neg_infinity(),
+            },
+        }
+    }
+
+    fn drain(mut queue: EventQueue<f64>) -> Vec<Rc<SweepEvent<f64>>> {
+        let mut events = Vec::new();
+        while let Some(Reverse(event)) = queue.pop() {
+            events.push(event);
+        }
+        events
+    }
+
+    #[test]
+    fn process_ring_assigns_one_edge_index_per_surviving_edge() {
+        let square = LineString(vec![
+            Coordinate { x: 0.0, y: 0.0 },
+            Coordinate { x: 1.0, y: 0.0 },
+            Coordinate { x: 1.0, y: 1.0 },
+            Coordinate { x: 0.0, y: 1.0 },
+            Coordinate { x: 0.0, y: 0.0 },
+        ]);
+
+        let mut queue: EventQueue<f64> = EventQueue::new();
+        let mut bbox = empty_bbox();
+        process_ring(1, &square, true, true, &mut bbox, &mut queue);
+
+        let events = drain(queue);
+        assert_eq!(events.len(), 8, "4 edges * 2 endpoint events each");
+
+        let mut indices: Vec<usize> = events.iter().map(|e| e.get_edge_index()).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+        assert!(events.iter().all(|e| e.get_ring_edge_count() == 4));
+    }
+
+    #[test]
+    fn process_ring_drops_degenerate_edges_before_assigning_edge_index() {
+        // The repeated (1,0) point makes one window a zero-length edge that must be filtered
+        // out before edge indices (and the ring's total edge count) are assigned, rather than
+        // leaving a gap or an inflated count.
+        let square_with_repeat = LineString(vec![
+            Coordinate { x: 0.0, y: 0.0 },
+            Coordinate { x: 1.0, y: 0.0 },
+            Coordinate { x: 1.0, y: 0.0 },
+            Coordinate { x: 1.0, y: 1.0 },
+            Coordinate { x: 0.0, y: 1.0 },
+            Coordinate { x: 0.0, y: 0.0 },
+        ]);
+
+        let mut queue: EventQueue<f64> = EventQueue::new();
+        let mut bbox = empty_bbox();
+        process_ring(1, &square_with_repeat, true, true, &mut bbox, &mut queue);
+
+        let events = drain(queue);
+        assert_eq!(events.len(), 8, "the degenerate edge should not produce any events");
+        assert!(events.iter().all(|e| e.get_ring_edge_count() == 4));
+
+        let mut indices: Vec<usize> = events.iter().map(|e| e.get_edge_index()).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        assert_eq!(indices, vec![0, 1, 2, 3], "edge indices must stay contiguous, with no gap left by the drop");
+    }
+
+    #[test]
+    fn process_ring_grows_the_bbox_to_the_ring_extent() {
+        let triangle = LineString(vec![
+            Coordinate { x: -2.0, y: 1.0 },
+            Coordinate { x: 5.0, y: -3.0 },
+            Coordinate { x: 1.0, y: 8.0 },
+            Coordinate { x: -2.0, y: 1.0 },
+        ]);
+
+        let mut queue: EventQueue<f64> = EventQueue::new();
+        let mut bbox = empty_bbox();
+        process_ring(1, &triangle, true, true, &mut bbox, &mut queue);
+
+        assert_eq!(bbox.min, Coordinate { x: -2.0, y: -3.0 });
+        assert_eq!(bbox.max, Coordinate { x: 5.0, y: 8.0 });
+    }
+
+    #[test]
+    fn process_polygon_gives_the_exterior_and_each_hole_a_distinct_contour_id() {
+        let polygon = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 10.0, y: 0.0 },
+                Coordinate { x: 10.0, y: 10.0 },
+                Coordinate { x: 0.0, y: 10.0 },
+                Coordinate { x: 0.0, y: 0.0 },
+            ]),
+            vec![LineString(vec![
+                Coordinate { x: 2.0, y: 2.0 },
+                Coordinate { x: 4.0, y: 2.0 },
+                Coordinate { x: 4.0, y: 4.0 },
+                Coordinate { x: 2.0, y: 4.0 },
+                Coordinate { x: 2.0, y: 2.0 },
+            ])],
+        );
+
+        let mut queue: EventQueue<f64> = EventQueue::new();
+        let mut bbox = empty_bbox();
+        let mut contour_id = 0;
+        process_polygon(&mut contour_id, &polygon, true, &mut bbox, &mut queue);
+
+        let events = drain(queue);
+        let exterior_contour_ids: Vec<usize> = events
+            .iter()
+            .filter(|e| e.is_exterior_ring())
+            .map(|e| e.contour_id)
+            .collect();
+        let hole_contour_ids: Vec<usize> = events
+            .iter()
+            .filter(|e| !e.is_exterior_ring())
+            .map(|e| e.contour_id)
+            .collect();
+
+        assert!(exterior_contour_ids.iter().all(|&id| id == 1));
+        assert!(hole_contour_ids.iter().all(|&id| id == 2));
+        assert_eq!(contour_id, 2);
+    }
+
+    #[test]
+    fn fill_queue_marks_subject_and_clipping_events_and_grows_separate_bboxes() {
+        let subject = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 1.0, y: 0.0 },
+                Coordinate { x: 1.0, y: 1.0 },
+                Coordinate { x: 0.0, y: 1.0 },
+                Coordinate { x: 0.0, y: 0.0 },
+            ]),
+            vec![],
+        );
+        let clipping = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 10.0, y: 10.0 },
+                Coordinate { x: 12.0, y: 10.0 },
+                Coordinate { x: 12.0, y: 12.0 },
+                Coordinate { x: 10.0, y: 12.0 },
+                Coordinate { x: 10.0, y: 10.0 },
+            ]),
+            vec![],
+        );
+
+        let mut sbbox = empty_bbox();
+        let mut cbbox = empty_bbox();
+        let queue = fill_queue(&[subject], &[clipping], &mut sbbox, &mut cbbox, Operation::Intersection);
+
+        let events = drain(queue);
+        assert!(events.iter().filter(|e| e.is_subject).count() == 8);
+        assert!(events.iter().filter(|e| !e.is_subject).count() == 8);
+
+        assert_eq!(sbbox.min, Coordinate { x: 0.0, y: 0.0 });
+        assert_eq!(sbbox.max, Coordinate { x: 1.0, y: 1.0 });
+        assert_eq!(cbbox.min, Coordinate { x: 10.0, y: 10.0 });
+        assert_eq!(cbbox.max, Coordinate { x: 12.0, y: 12.0 });
+    }
+}
@@ -0,0 +1,255 @@
+use super::fill_queue::fill_queue;
+use super::segment_intersection::{intersection, LineIntersection};
+use super::sweep_event::SweepEvent;
+use super::Operation;
+use geo::{Coordinate, Line, Polygon, Rect};
+use num_traits::Float;
+use std::cmp::Reverse;
+use std::rc::Rc;
+
+/// How two boundaries meet at a reported intersection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntersectionKind {
+    /// The segments cross transversally at an interior point of both.
+    Crossing,
+    /// An endpoint of one segment touches the other.
+    Touch,
+    /// The segments are collinear and overlap along a sub-segment.
+    Overlap,
+}
+
+/// One place where the boundaries given to [`intersections`] (or [`self_intersections`]) meet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntersectionReport<F>
+where
+    F: Float,
+{
+    pub kind: IntersectionKind,
+    /// Set for `Crossing` and `Touch`; `None` for `Overlap`, which carries `overlap` instead.
+    pub point: Option<Coordinate<F>>,
+    /// Set for `Overlap`; `None` for `Crossing` and `Touch`.
+    pub overlap: Option<Line<F>>,
+    /// True when this report came from [`self_intersections`] rather than a two-geometry call.
+    pub self_intersection: bool,
+}
+
+fn empty_bbox<F: Float>() -> Rect<F> {
+    Rect {
+        min: Coordinate {
+            x: F::infinity(),
+            y: F::infinity(),
+        },
+        max: Coordinate {
+            x: F::neg_infinity(),
+            y: F::neg_infinity(),
+        },
+    }
+}
+
+/// True when `p` is simply the shared vertex between two ring-adjacent edges of the same
+/// contour, rather than a genuine self-intersection (e.g. a ring revisiting the same coordinate
+/// at two unrelated edges, a pinch point, which *is* a self-intersection).
+fn is_ring_adjacent_touch<F: Float>(p: Coordinate<F>, a: &Rc<SweepEvent<F>>, b: &Rc<SweepEvent<F>>) -> bool {
+    if a.contour_id != b.contour_id {
+        return false;
+    }
+
+    let n = a.get_ring_edge_count();
+    if n == 0 || n != b.get_ring_edge_count() {
+        return false;
+    }
+
+    let (ia, ib) = (a.get_edge_index(), b.get_edge_index());
+    let diff = if ia > ib { ia - ib } else { ib - ia };
+    if diff != 1 && diff != n - 1 {
+        // Not consecutive edges of the ring (even accounting for wraparound past the last
+        // edge), so a shared coordinate here is a genuine self-intersection.
+        return false;
+    }
+
+    let a_other = match a.get_other_event() {
+        Some(other) => other,
+        None => return false,
+    };
+    let b_other = match b.get_other_event() {
+        Some(other) => other,
+        None => return false,
+    };
+    (p == a.point || p == a_other.point) && (p == b.point || p == b_other.point)
+}
+
+/// Runs `fill_queue` + the `possible_intersection` detection logic (without its splitting or
+/// connector stages) and reports every place the two active-segment sets meet. `self_check`
+/// also compares segments of the same input with each other and flags every report as a
+/// self-intersection. In both modes, a vertex shared by two ring-adjacent edges of the same
+/// contour is skipped rather than reported.
+fn sweep_for_reports<F>(
+    subject: &[Polygon<F>],
+    clipping: &[Polygon<F>],
+    self_check: bool,
+) -> Vec<IntersectionReport<F>>
+where
+    F: Float,
+{
+    let mut sbbox = empty_bbox();
+    let mut cbbox = empty_bbox();
+    let mut queue = fill_queue(subject, clipping, &mut sbbox, &mut cbbox, Operation::Intersection);
+
+    let mut active: Vec<Rc<SweepEvent<F>>> = Vec::new();
+    let mut reports = Vec::new();
+
+    while let Some(event) = queue.pop().map(|Reverse(event)| event) {
+        if event.is_left() {
+            let other_point = match event.get_other_event() {
+                Some(other) => other.point,
+                None => continue,
+            };
+
+            for active_event in &active {
+                // In the two-geometry case only a subject/clipping pair can produce a meaningful
+                // report; comparing two events from the same input would just rediscover that
+                // input's own ring structure (e.g. its own corners), which is what `self_check`
+                // is for. `self_check` itself never sees mixed inputs, since `self_intersections`
+                // calls with an empty `clipping`, so this only ever filters the cross-check case.
+                if !self_check && event.is_subject == active_event.is_subject {
+                    continue;
+                }
+
+                let active_other_point = match active_event.get_other_event() {
+                    Some(other) => other.point,
+                    None => continue,
+                };
+
+                match intersection(event.point, other_point, active_event.point, active_other_point) {
+                    LineIntersection::None => {}
+                    LineIntersection::Point(p) => {
+                        if is_ring_adjacent_touch(p, &event, active_event) {
+                            continue;
+                        }
+                        let touch = p == event.point
+                            || p == other_point
+                            || p == active_event.point
+                            || p == active_other_point;
+                        reports.push(IntersectionReport {
+                            kind: if touch { IntersectionKind::Touch } else { IntersectionKind::Crossing },
+                            point: Some(p),
+                            overlap: None,
+                            self_intersection: self_check,
+                        });
+                    }
+                    LineIntersection::Overlap(a, b) => {
+                        reports.push(IntersectionReport {
+                            kind: IntersectionKind::Overlap,
+                            point: None,
+                            overlap: Some(Line::new(a, b)),
+                            self_intersection: self_check,
+                        });
+                    }
+                }
+            }
+
+            active.push(event);
+        } else if let Some(other) = event.get_other_event() {
+            active.retain(|e| !Rc::ptr_eq(e, &other));
+        }
+    }
+
+    // A vertex shared by more than two edges (e.g. two squares touching at one corner, where
+    // both the subject's and the clipping's edges meeting there are compared pairwise against
+    // each other) is discovered once per edge-pair rather than once per vertex, so the same
+    // report can otherwise be pushed several times for a single real intersection.
+    let mut deduped: Vec<IntersectionReport<F>> = Vec::with_capacity(reports.len());
+    for report in reports {
+        if !deduped.contains(&report) {
+            deduped.push(report);
+        }
+    }
+    deduped
+}
+
+/// Finds every point where the boundaries of `subject` and `clipping` cross, touch, or overlap,
+/// without running the connector stage that would build a boolean-op result polygon. Reuses the
+/// same `fill_queue` event ordering the boolean operations do, so only segments that are
+/// simultaneously active in the sweep are ever compared, rather than every edge against every
+/// other edge up front — but the active set itself is still checked pairwise with no further
+/// pruning, so this is `O(n log n)` to build and order the queue with an `O(m)`-per-event,
+/// worst-case-`O(n^2)` overall comparison cost, not the neighbor-limited sweep a classic
+/// Bentley-Ottmann implementation would use.
+pub fn intersections<F>(subject: &[Polygon<F>], clipping: &[Polygon<F>]) -> Vec<IntersectionReport<F>>
+where
+    F: Float,
+{
+    sweep_for_reports(subject, clipping, false)
+}
+
+/// Finds every place `geometry`'s own boundary crosses or overlaps itself, i.e. answers "is this
+/// polygon simple?". Vertices shared between ring-adjacent edges are not reported, since those
+/// are a normal part of a simple ring rather than a self-intersection.
+pub fn self_intersections<F>(geometry: &[Polygon<F>]) -> Vec<IntersectionReport<F>>
+where
+    F: Float,
+{
+    sweep_for_reports(geometry, &[], true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::LineString;
+
+    fn square(x0: f64, y0: f64, side: f64) -> Polygon<f64> {
+        let x1 = x0 + side;
+        let y1 = y0 + side;
+        Polygon::new(
+            LineString(vec![
+                Coordinate { x: x0, y: y0 },
+                Coordinate { x: x1, y: y0 },
+                Coordinate { x: x1, y: y1 },
+                Coordinate { x: x0, y: y1 },
+                Coordinate { x: x0, y: y0 },
+            ]),
+            vec![],
+        )
+    }
+
+    fn bowtie() -> Polygon<f64> {
+        // A figure-eight ring that crosses its own boundary at the origin.
+        Polygon::new(
+            LineString(vec![
+                Coordinate { x: -1.0, y: -1.0 },
+                Coordinate { x: 1.0, y: 1.0 },
+                Coordinate { x: 1.0, y: -1.0 },
+                Coordinate { x: -1.0, y: 1.0 },
+                Coordinate { x: -1.0, y: -1.0 },
+            ]),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn intersections_reports_nothing_for_disjoint_squares() {
+        let reports = intersections(&[square(0.0, 0.0, 1.0)], &[square(10.0, 10.0, 1.0)]);
+        assert!(reports.is_empty(), "expected no reports, got {:?}", reports);
+    }
+
+    #[test]
+    fn intersections_reports_a_touch_for_corner_sharing_squares() {
+        // Second square's bottom-left corner sits exactly on the first square's top-right corner.
+        let reports = intersections(&[square(0.0, 0.0, 1.0)], &[square(1.0, 1.0, 1.0)]);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].kind, IntersectionKind::Touch);
+        assert_eq!(reports[0].point, Some(Coordinate { x: 1.0, y: 1.0 }));
+    }
+
+    #[test]
+    fn self_intersections_reports_nothing_for_a_simple_polygon() {
+        let reports = self_intersections(&[square(0.0, 0.0, 1.0)]);
+        assert!(reports.is_empty(), "expected no reports, got {:?}", reports);
+    }
+
+    #[test]
+    fn self_intersections_reports_a_crossing_for_a_bowtie() {
+        let reports = self_intersections(&[bowtie()]);
+        assert!(reports.iter().any(|r| r.kind == IntersectionKind::Crossing));
+    }
+}
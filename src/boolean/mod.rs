@@ -0,0 +1,21 @@
+mod divide_segment;
+mod fill_queue;
+mod intersections;
+mod possible_intersection;
+mod segment_intersection;
+mod sweep_event;
+
+pub use intersections::{intersections, self_intersections, IntersectionKind, IntersectionReport};
+
+/// Which boolean set operation a sweep is being run for.
+///
+/// Most of the sweep machinery (queue filling, event ordering, intersection handling) is the
+/// same regardless of the operation; it mainly affects how the connector stage decides which
+/// edges end up in the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Intersection,
+    Union,
+    Xor,
+    Difference,
+}
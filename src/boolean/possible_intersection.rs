@@ -1,15 +1,44 @@
 use super::divide_segment::divide_segment;
 use super::segment_intersection::{intersection, LineIntersection};
-use super::sweep_event::{EdgeType, SweepEvent};
+use super::sweep_event::{EdgeType, EventQueue, SweepEvent};
+use geo::Coordinate;
 use num_traits::Float;
-use std::collections::BinaryHeap;
 use std::rc::Rc;
 
-pub fn possible_intersection<F>(
-    se1: Rc<SweepEvent<F>>,
-    se2: Rc<SweepEvent<F>>,
-    queue: &mut BinaryHeap<Rc<SweepEvent<F>>>,
-) -> u8
+/// Computes the Z (or other interpolated attribute) that a new vertex at `p` should carry,
+/// given that `p` lies on (or splits) the segment `se` -> `se.get_other_event()`.
+///
+/// If `p` coincides with one of the segment's existing endpoints, that endpoint's Z is copied
+/// verbatim instead of being recomputed, to avoid rounding drift. Returns `None` when either
+/// endpoint has no Z, since there is then nothing meaningful to interpolate.
+fn z_for_split<F>(se: &Rc<SweepEvent<F>>, p: Coordinate<F>) -> Option<F>
+where
+    F: Float,
+{
+    let other = se.get_other_event()?;
+    let (za, zb) = match (se.get_z(), other.get_z()) {
+        (Some(za), Some(zb)) => (za, zb),
+        _ => return None,
+    };
+
+    if p == se.point {
+        return Some(za);
+    }
+    if p == other.point {
+        return Some(zb);
+    }
+
+    // Use whichever axis is dominant for this segment to avoid dividing by ~0.
+    let t = if (other.point.x - se.point.x).abs() >= (other.point.y - se.point.y).abs() {
+        (p.x - se.point.x) / (other.point.x - se.point.x)
+    } else {
+        (p.y - se.point.y) / (other.point.y - se.point.y)
+    };
+
+    Some(za + t * (zb - za))
+}
+
+pub fn possible_intersection<F>(se1: Rc<SweepEvent<F>>, se2: Rc<SweepEvent<F>>, queue: &mut EventQueue<F>) -> u8
 where
     F: Float,
 {
@@ -23,10 +52,12 @@ where
         LineIntersection::Point(_) if se1.point == se2.point && other1.point == other2.point => 0, // the line segments intersect at an endpoint of both line segments
         LineIntersection::Point(inter) => {
             if se1.point != inter && other1.point != inter {
-                divide_segment(&se1, inter, queue)
+                let z = z_for_split(&se1, inter);
+                divide_segment(&se1, inter, queue, z)
             }
             if se2.point != inter && other2.point != inter {
-                divide_segment(&se2, inter, queue)
+                let z = z_for_split(&se2, inter);
+                divide_segment(&se2, inter, queue, z)
             }
             1
         }
@@ -66,46 +97,103 @@ where
                 }
 
                 if left_coincide && !right_coincide {
-                    divide_segment(&events[1].1, events[0].0.point, queue)
+                    let p = events[0].0.point;
+                    let z = z_for_split(&events[1].1, p);
+                    divide_segment(&events[1].1, p, queue, z)
                 }
                 return 2;
             }
 
             if right_coincide {
                 // the line segments share the right endpoint
-                divide_segment(&events[0].0, events[1].0.point, queue);
+                let p = events[1].0.point;
+                let z = z_for_split(&events[0].0, p);
+                divide_segment(&events[0].0, p, queue, z);
                 return 3;
             }
 
             if !Rc::ptr_eq(&events[0].0, &events[3].1) {
                 // no line segment includes totally the other one
-                divide_segment(&events[0].0, events[1].0.point, queue);
-                divide_segment(&events[1].0, events[2].0.point, queue);
+                let p1 = events[1].0.point;
+                let z1 = z_for_split(&events[0].0, p1);
+                divide_segment(&events[0].0, p1, queue, z1);
+                let p2 = events[2].0.point;
+                let z2 = z_for_split(&events[1].0, p2);
+                divide_segment(&events[1].0, p2, queue, z2);
                 return 3;
             }
 
             // one line segment includes the other one
-            divide_segment(&events[0].0, events[1].0.point, queue);
-            divide_segment(&events[3].1, events[2].0.point, queue);
+            let p1 = events[1].0.point;
+            let z1 = z_for_split(&events[0].0, p1);
+            divide_segment(&events[0].0, p1, queue, z1);
+            let p2 = events[2].0.point;
+            let z2 = z_for_split(&events[3].1, p2);
+            divide_segment(&events[3].1, p2, queue, z2);
 
             3
         }
     }
 }
 
+#[cfg(test)]
+mod z_for_split_test {
+    use super::*;
+    use std::rc::Weak;
+
+    // Returns (event, other) and the caller must hold both: `event` only keeps a `Weak` ref to
+    // `other` (as real sweep events do), so if `other`'s last strong ref were dropped here,
+    // `event.get_other_event()` would see a dead `Weak` and `z_for_split` would short-circuit.
+    fn segment(a: Coordinate<f64>, za: f64, b: Coordinate<f64>, zb: f64) -> (Rc<SweepEvent<f64>>, Rc<SweepEvent<f64>>) {
+        let other = SweepEvent::new(0, b, false, Weak::new(), true, true);
+        let event = SweepEvent::new(0, a, true, Rc::downgrade(&other), true, true);
+        event.set_other_event(&other);
+        event.set_z(za);
+        other.set_z(zb);
+        (event, other)
+    }
+
+    #[test]
+    fn copies_the_left_endpoint_z_when_the_split_point_is_the_left_endpoint() {
+        let (se, _other) = segment(Coordinate { x: 0.0, y: 0.0 }, 10.0, Coordinate { x: 10.0, y: 0.0 }, 20.0);
+        assert_eq!(z_for_split(&se, Coordinate { x: 0.0, y: 0.0 }), Some(10.0));
+    }
+
+    #[test]
+    fn copies_the_right_endpoint_z_when_the_split_point_is_the_right_endpoint() {
+        let (se, _other) = segment(Coordinate { x: 0.0, y: 0.0 }, 10.0, Coordinate { x: 10.0, y: 0.0 }, 20.0);
+        assert_eq!(z_for_split(&se, Coordinate { x: 10.0, y: 0.0 }), Some(20.0));
+    }
+
+    #[test]
+    fn interpolates_z_at_an_interior_split_point() {
+        let (se, _other) = segment(Coordinate { x: 0.0, y: 0.0 }, 10.0, Coordinate { x: 10.0, y: 0.0 }, 20.0);
+        assert_eq!(z_for_split(&se, Coordinate { x: 4.0, y: 0.0 }), Some(14.0));
+    }
+
+    #[test]
+    fn returns_none_when_either_endpoint_has_no_z() {
+        let other = SweepEvent::new(0, Coordinate { x: 10.0, y: 0.0 }, false, Weak::new(), true, true);
+        let event = SweepEvent::new(0, Coordinate { x: 0.0, y: 0.0 }, true, Rc::downgrade(&other), true, true);
+        event.set_other_event(&other);
+        event.set_z(10.0);
+        // `other` never gets a Z.
+        assert_eq!(z_for_split(&event, Coordinate { x: 4.0, y: 0.0 }), None);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::super::compare_segments::compare_segments;
     use super::super::fill_queue::fill_queue;
     use super::super::helper::test::fixture_shapes;
     use super::super::subdivide_segments::subdivide;
-    use super::super::sweep_event::SweepEvent;
+    use super::super::sweep_event::{EventQueue, SweepEvent};
     use super::super::Operation;
     use super::*;
     use geo::{Coordinate, Rect};
     use splay::SplaySet;
     use std::cmp::Ordering;
-    use std::collections::BinaryHeap;
     use std::rc::{Rc, Weak};
 
     fn make_simple(
@@ -122,7 +210,7 @@ mod test {
     #[test]
     fn test_possible_intersection() {
         let (s, c) = fixture_shapes("two_shapes.geojson");
-        let mut q: BinaryHeap<Rc<SweepEvent<f64>>> = BinaryHeap::new();
+        let mut q: EventQueue<f64> = EventQueue::new();
 
         let (se1, _other1) = make_simple(s.exterior.0[3], s.exterior.0[2], true);
         let (se2, _other2) = make_simple(c.exterior.0[0], c.exterior.0[1], false);
@@ -130,7 +218,7 @@ mod test {
         assert_eq!(possible_intersection(se1.clone(), se2.clone(), &mut q), 1);
         assert_eq!(q.len(), 4);
 
-        let mut e = q.pop().unwrap();
+        let mut e = q.pop().unwrap().0;
         assert_eq!(
             e.point,
             Coordinate {
@@ -140,7 +228,7 @@ mod test {
         );
         assert_eq!(e.get_other_event().unwrap().point, Coordinate { x: 56.0, y: 181.0 });
 
-        e = q.pop().unwrap();
+        e = q.pop().unwrap().0;
         assert_eq!(
             e.point,
             Coordinate {
@@ -150,7 +238,7 @@ mod test {
         );
         assert_eq!(e.get_other_event().unwrap().point, Coordinate { x: 16.0, y: 282.0 });
 
-        e = q.pop().unwrap();
+        e = q.pop().unwrap().0;
         assert_eq!(
             e.point,
             Coordinate {
@@ -160,7 +248,7 @@ mod test {
         );
         assert_eq!(e.get_other_event().unwrap().point, Coordinate { x: 153.0, y: 203.5 });
 
-        e = q.pop().unwrap();
+        e = q.pop().unwrap().0;
         assert_eq!(
             e.point,
             Coordinate {
@@ -0,0 +1,280 @@
+use geo::Coordinate;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineIntersection<F>
+where
+    F: Float,
+{
+    None,
+    Point(Coordinate<F>),
+    Overlap(Coordinate<F>, Coordinate<F>),
+}
+
+fn cross<F: Float>(a: Coordinate<F>, b: Coordinate<F>) -> F {
+    a.x * b.y - a.y * b.x
+}
+
+fn sub<F: Float>(a: Coordinate<F>, b: Coordinate<F>) -> Coordinate<F> {
+    Coordinate {
+        x: a.x - b.x,
+        y: a.y - b.y,
+    }
+}
+
+fn sign<F: Float>(v: F) -> i8 {
+    if v > F::zero() {
+        1
+    } else if v < F::zero() {
+        -1
+    } else {
+        0
+    }
+}
+
+// -- Shewchuk-style adaptive-precision orientation test --------------------------------------
+//
+// `orient2d` answers "is c left of, right of, or on the line through a->b" the same way a raw
+// 2x2 determinant would, but only trusts the fast floating-point determinant when its forward
+// error bound proves the sign can't have flipped. Otherwise it recomputes the two cross-product
+// terms as error-free two-product expansions, combines their high parts with a two-sum, and
+// adds the low-order parts back before taking the sign, which resolves nearly-collinear and
+// nearly-coincident cases that the fast determinant cannot. This is not an exact predicate:
+// the coordinate differences are rounded before the products, and the final addition of the
+// low-order parts is rounded too.
+
+fn splitter<F: Float>() -> F {
+    let bits = -F::epsilon().log2();
+    let half = (bits / (F::one() + F::one())).ceil();
+    let two = F::one() + F::one();
+    two.powf(half + F::one()) + F::one()
+}
+
+fn split<F: Float>(a: F) -> (F, F) {
+    let c = splitter::<F>() * a;
+    let hi = c - (c - a);
+    let lo = a - hi;
+    (hi, lo)
+}
+
+fn two_product<F: Float>(a: F, b: F) -> (F, F) {
+    let x = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let err = ((a_hi * b_hi - x) + a_hi * b_lo + a_lo * b_hi) + a_lo * b_lo;
+    (x, err)
+}
+
+fn two_sum<F: Float>(a: F, b: F) -> (F, F) {
+    let x = a + b;
+    let bv = x - a;
+    let av = x - bv;
+    let ar = a - av;
+    let br = b - bv;
+    (x, ar + br)
+}
+
+fn error_bound<F: Float>() -> F {
+    let three = F::one() + F::one() + F::one();
+    let sixteen = F::from(16.0).unwrap();
+    (three + sixteen * F::epsilon()) * F::epsilon()
+}
+
+fn orient2d<F: Float>(a: Coordinate<F>, b: Coordinate<F>, c: Coordinate<F>) -> i8 {
+    let acx = a.x - c.x;
+    let bcx = b.x - c.x;
+    let acy = a.y - c.y;
+    let bcy = b.y - c.y;
+
+    let t1 = acx * bcy;
+    let t2 = acy * bcx;
+    let det = t1 - t2;
+
+    let err = error_bound::<F>() * (t1.abs() + t2.abs());
+    if det.abs() > err {
+        return sign(det);
+    }
+
+    let (t1_hi, t1_lo) = two_product(acx, bcy);
+    let (t2_hi, t2_lo) = two_product(acy, bcx);
+    let (s_hi, s_lo) = two_sum(t1_hi, -t2_hi);
+    let exact = s_hi + (s_lo + t1_lo - t2_lo);
+
+    sign(exact)
+}
+
+/// Orders `a`, `b` ascending along whichever axis the segment spans more of (its "dominant"
+/// axis). Arranging both segments this way before testing gives a consistent axis to reduce a
+/// collinear case to an interval overlap on; since the values returned are the endpoints
+/// themselves rather than indices into the caller's original arrangement, there is nothing for a
+/// caller to map back regardless of which order they came in.
+fn arrange<F: Float>(a: Coordinate<F>, b: Coordinate<F>) -> (Coordinate<F>, Coordinate<F>) {
+    let dominant_x = (b.x - a.x).abs() >= (b.y - a.y).abs();
+    let unordered = if dominant_x { a.x > b.x } else { a.y > b.y };
+    if unordered {
+        (b, a)
+    } else {
+        (a, b)
+    }
+}
+
+fn on_bounding_box<F: Float>(a: Coordinate<F>, b: Coordinate<F>, p: Coordinate<F>) -> bool {
+    p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
+
+fn crossing_point<F: Float>(
+    a1: Coordinate<F>,
+    a2: Coordinate<F>,
+    b1: Coordinate<F>,
+    b2: Coordinate<F>,
+) -> Coordinate<F> {
+    let d1 = sub(a2, a1);
+    let d2 = sub(b2, b1);
+    let e = sub(b1, a1);
+    let s = cross(e, d2) / cross(d1, d2);
+    a1 + d1 * s
+}
+
+fn collinear_overlap<F: Float>(
+    p1: Coordinate<F>,
+    p2: Coordinate<F>,
+    q1: Coordinate<F>,
+    q2: Coordinate<F>,
+) -> LineIntersection<F> {
+    let use_x = (p2.x - p1.x).abs() >= (p2.y - p1.y).abs();
+
+    let (lo_p, hi_p) = if use_x { (p1.x, p2.x) } else { (p1.y, p2.y) };
+    let (q_a, q_b) = if use_x { (q1.x, q2.x) } else { (q1.y, q2.y) };
+    let (lo_q, hi_q) = if q_a <= q_b { (q_a, q_b) } else { (q_b, q_a) };
+
+    let lo = lo_p.max(lo_q);
+    let hi = hi_p.min(hi_q);
+
+    if lo > hi {
+        return LineIntersection::None;
+    }
+
+    let at = |v: F| -> Coordinate<F> {
+        if use_x {
+            let t = (v - p1.x) / (p2.x - p1.x);
+            Coordinate {
+                x: v,
+                y: p1.y + t * (p2.y - p1.y),
+            }
+        } else {
+            let t = (v - p1.y) / (p2.y - p1.y);
+            Coordinate {
+                x: p1.x + t * (p2.x - p1.x),
+                y: v,
+            }
+        }
+    };
+
+    if lo == hi {
+        LineIntersection::Point(at(lo))
+    } else {
+        LineIntersection::Overlap(at(lo), at(hi))
+    }
+}
+
+/// Classifies how the segments `a1`->`a2` and `b1`->`b2` relate to each other.
+///
+/// Modeled on Boost.Geometry's cartesian segment strategy: rather than reconstructing a
+/// floating-point crossing point up front (which is what leads `possible_intersection` astray
+/// on nearly-collinear or nearly-coincident input), each segment is first canonically arranged
+/// along its dominant axis, then the relationship is decided from the *signs* of the four
+/// endpoint orientations: opposite signs on both sides means a proper crossing, a zero sign
+/// means an endpoint touches the other segment, all four zero means collinear (reduced to an
+/// interval overlap test on the shared axis), and anything else means the segments are disjoint.
+/// The orientation signs themselves come from an adaptive-precision predicate, so the
+/// classification stays consistent with `compare_segments` even right at the margins.
+pub fn intersection<F>(
+    a1: Coordinate<F>,
+    a2: Coordinate<F>,
+    b1: Coordinate<F>,
+    b2: Coordinate<F>,
+) -> LineIntersection<F>
+where
+    F: Float,
+{
+    let (p1, p2) = arrange(a1, a2);
+    let (q1, q2) = arrange(b1, b2);
+
+    let o1 = orient2d(p1, p2, q1);
+    let o2 = orient2d(p1, p2, q2);
+    let o3 = orient2d(q1, q2, p1);
+    let o4 = orient2d(q1, q2, p2);
+
+    if o1 != 0 && o2 != 0 && o1 != o2 && o3 != 0 && o4 != 0 && o3 != o4 {
+        return LineIntersection::Point(crossing_point(p1, p2, q1, q2));
+    }
+
+    if o1 == 0 && o2 == 0 && o3 == 0 && o4 == 0 {
+        return collinear_overlap(p1, p2, q1, q2);
+    }
+
+    if o1 == 0 && on_bounding_box(p1, p2, q1) {
+        return LineIntersection::Point(q1);
+    }
+    if o2 == 0 && on_bounding_box(p1, p2, q2) {
+        return LineIntersection::Point(q2);
+    }
+    if o3 == 0 && on_bounding_box(q1, q2, p1) {
+        return LineIntersection::Point(p1);
+    }
+    if o4 == 0 && on_bounding_box(q1, q2, p2) {
+        return LineIntersection::Point(p2);
+    }
+
+    LineIntersection::None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn c(x: f64, y: f64) -> Coordinate<f64> {
+        Coordinate { x, y }
+    }
+
+    #[test]
+    fn intersection_reports_a_proper_crossing() {
+        let result = intersection(c(0.0, 0.0), c(2.0, 2.0), c(0.0, 2.0), c(2.0, 0.0));
+        assert_eq!(result, LineIntersection::Point(c(1.0, 1.0)));
+    }
+
+    #[test]
+    fn intersection_reports_an_endpoint_touch() {
+        let result = intersection(c(0.0, 0.0), c(2.0, 0.0), c(2.0, 0.0), c(2.0, 2.0));
+        assert_eq!(result, LineIntersection::Point(c(2.0, 0.0)));
+    }
+
+    #[test]
+    fn intersection_reports_none_for_disjoint_segments() {
+        let result = intersection(c(0.0, 0.0), c(1.0, 0.0), c(0.0, 5.0), c(1.0, 5.0));
+        assert_eq!(result, LineIntersection::None);
+    }
+
+    #[test]
+    fn intersection_reports_a_collinear_overlap() {
+        let result = intersection(c(0.0, 0.0), c(4.0, 0.0), c(2.0, 0.0), c(6.0, 0.0));
+        assert_eq!(result, LineIntersection::Overlap(c(2.0, 0.0), c(4.0, 0.0)));
+    }
+
+    #[test]
+    fn orient2d_is_zero_for_exactly_collinear_points() {
+        assert_eq!(orient2d(c(0.0, 0.0), c(1.0, 1.0), c(2.0, 2.0)), 0);
+    }
+
+    #[test]
+    fn orient2d_resolves_a_near_collinear_case_the_fast_path_cannot() {
+        // Without its 2e-15 y offset, `p` would be exactly twice `b`, on the line `a`->`b`. The
+        // offset is small enough relative to the (1e8, 1) magnitudes that the fast `t1 - t2`
+        // determinant falls inside its error bound, so its sign is not trusted and the
+        // two-product/two-sum recombination decides the true (barely positive) sign.
+        let a = c(0.0, 0.0);
+        let b = c(1.0e8, 1.0);
+        let p = c(2.0e8, 2.0 + 2.0e-15);
+        assert_eq!(orient2d(a, b, p), 1);
+    }
+}
@@ -0,0 +1,197 @@
+use geo::Coordinate;
+use num_traits::Float;
+use std::cell::{Cell, RefCell};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::rc::{Rc, Weak};
+
+/// The sweep's pending-event queue. `SweepEvent`'s `Ord` is ascending in sweep order (smaller
+/// coordinates first), but `BinaryHeap` is a max-heap, so events are wrapped in `Reverse` here
+/// to make `queue.pop()` return them in ascending order instead.
+pub type EventQueue<F> = BinaryHeap<Reverse<Rc<SweepEvent<F>>>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeType {
+    Normal,
+    NonContributing,
+    SameTransition,
+    DifferentTransition,
+}
+
+/// One endpoint of a segment being swept, paired with its other endpoint via `other_event`.
+///
+/// Besides the X/Y carried by `point`, a `SweepEvent` can carry an optional Z (or any other
+/// attribute that should be linearly interpolated across synthesized intersection vertices).
+/// It defaults to `None` and is only ever set by callers that care about a third dimension,
+/// so existing two-dimensional uses of the sweep are unaffected.
+///
+/// Note this is currently scaffolding rather than an end-to-end feature: this tree has no
+/// connector stage yet to build a result `Polygon`/`MultiPolygon` out of the swept events, and
+/// `fill_queue` (the only place events are created from real input) never calls `set_z`, since
+/// `geo::Coordinate<F>` has no Z of its own to read one from. Until both of those land, Z stays
+/// `None` for every event built from real input and `get_z()`/`set_z` only do anything useful in
+/// tests that construct events and call `possible_intersection`/`divide_segment` directly.
+pub struct SweepEvent<F>
+where
+    F: Float,
+{
+    pub contour_id: usize,
+    pub point: Coordinate<F>,
+    left: Cell<bool>,
+    other_event: RefCell<Option<Weak<SweepEvent<F>>>>,
+    pub is_subject: bool,
+    is_exterior_ring: Cell<bool>,
+    edge_type: Cell<EdgeType>,
+    in_out: Cell<bool>,
+    other_in_out: Cell<bool>,
+    in_result: Cell<bool>,
+    z: Cell<Option<F>>,
+    edge_index: Cell<usize>,
+    ring_edge_count: Cell<usize>,
+}
+
+impl<F> SweepEvent<F>
+where
+    F: Float,
+{
+    pub fn new(
+        contour_id: usize,
+        point: Coordinate<F>,
+        left: bool,
+        other_event: Weak<SweepEvent<F>>,
+        is_subject: bool,
+        is_exterior_ring: bool,
+    ) -> Rc<Self> {
+        Rc::new(SweepEvent {
+            contour_id,
+            point,
+            left: Cell::new(left),
+            other_event: RefCell::new(Some(other_event)),
+            is_subject,
+            is_exterior_ring: Cell::new(is_exterior_ring),
+            edge_type: Cell::new(EdgeType::Normal),
+            in_out: Cell::new(false),
+            other_in_out: Cell::new(false),
+            in_result: Cell::new(false),
+            z: Cell::new(None),
+            edge_index: Cell::new(0),
+            ring_edge_count: Cell::new(0),
+        })
+    }
+
+    pub fn is_left(&self) -> bool {
+        self.left.get()
+    }
+
+    pub fn get_other_event(&self) -> Option<Rc<SweepEvent<F>>> {
+        self.other_event.borrow().as_ref().and_then(Weak::upgrade)
+    }
+
+    pub fn set_other_event(&self, other: &Rc<SweepEvent<F>>) {
+        *self.other_event.borrow_mut() = Some(Rc::downgrade(other));
+    }
+
+    pub fn is_exterior_ring(&self) -> bool {
+        self.is_exterior_ring.get()
+    }
+
+    pub fn get_edge_type(&self) -> EdgeType {
+        self.edge_type.get()
+    }
+
+    pub fn set_edge_type(&self, edge_type: EdgeType) {
+        self.edge_type.set(edge_type);
+    }
+
+    pub fn is_in_out(&self) -> bool {
+        self.in_out.get()
+    }
+
+    pub fn set_in_out(&self, in_out: bool) {
+        self.in_out.set(in_out);
+    }
+
+    pub fn is_other_in_out(&self) -> bool {
+        self.other_in_out.get()
+    }
+
+    pub fn set_other_in_out(&self, other_in_out: bool) {
+        self.other_in_out.set(other_in_out);
+    }
+
+    pub fn is_in_result(&self) -> bool {
+        self.in_result.get()
+    }
+
+    pub fn set_in_result(&self, in_result: bool) {
+        self.in_result.set(in_result);
+    }
+
+    /// The Z (or other interpolated attribute) carried by this event's point, if any.
+    pub fn get_z(&self) -> Option<F> {
+        self.z.get()
+    }
+
+    pub fn set_z(&self, z: F) {
+        self.z.set(Some(z));
+    }
+
+    /// Which edge of its source ring this event's segment was built from, and how many edges
+    /// that ring has in total (both 0 for events that don't come directly from `fill_queue`,
+    /// such as the events `divide_segment` synthesizes at a split). Together these let a caller
+    /// tell two ring-adjacent edges (which legitimately share a vertex) apart from two edges
+    /// that merely happen to revisit the same coordinate elsewhere in the ring.
+    pub fn get_edge_index(&self) -> usize {
+        self.edge_index.get()
+    }
+
+    pub fn set_edge_index(&self, edge_index: usize, ring_edge_count: usize) {
+        self.edge_index.set(edge_index);
+        self.ring_edge_count.set(ring_edge_count);
+    }
+
+    pub fn get_ring_edge_count(&self) -> usize {
+        self.ring_edge_count.get()
+    }
+}
+
+impl<F> PartialEq for SweepEvent<F>
+where
+    F: Float,
+{
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl<F> Eq for SweepEvent<F> where F: Float {}
+
+impl<F> PartialOrd for SweepEvent<F>
+where
+    F: Float,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F> Ord for SweepEvent<F>
+where
+    F: Float,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.point.x.partial_cmp(&other.point.x) {
+            Some(Ordering::Equal) | None => {}
+            Some(ordering) => return ordering,
+        }
+        match self.point.y.partial_cmp(&other.point.y) {
+            Some(Ordering::Equal) | None => {}
+            Some(ordering) => return ordering,
+        }
+        // At the same point, a left endpoint orders before a right endpoint, so `EventQueue`
+        // pops it first. `bool` orders `false < true`, hence the swapped operands. Two events at
+        // the same point that are both left (or both right) compare `Equal`, and the order in
+        // which the queue pops them is not defined.
+        other.left.get().cmp(&self.left.get())
+    }
+}
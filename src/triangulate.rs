@@ -0,0 +1,331 @@
+use geo::{Coordinate, MultiPolygon, Polygon};
+use num_traits::Float;
+
+/// Triangulates a boolean-operation result (or any `Polygon`/`MultiPolygon`) by ear-clipping,
+/// so consumers that need triangles — rendering, FEM, collision — can get them in one call
+/// instead of bolting a separate triangulation library onto the output of an [`Operation`].
+///
+/// [`Operation`]: crate::boolean::Operation
+pub trait Triangulate<F>
+where
+    F: Float,
+{
+    /// Triangulates `self` by ear-clipping after bridging any holes into the exterior ring.
+    ///
+    /// If the ring handed to ear-clipping turns out to be malformed (self-intersecting, e.g. from
+    /// a degenerate bridge in [`bridge_holes`]), ear-clipping stops once it can no longer find a
+    /// clippable ear rather than looping forever, and this silently returns a triangle set that
+    /// covers less area than `self` — there is no error signaled for that case.
+    fn triangulate(&self) -> Vec<[Coordinate<F>; 3]>;
+}
+
+impl<F> Triangulate<F> for MultiPolygon<F>
+where
+    F: Float,
+{
+    fn triangulate(&self) -> Vec<[Coordinate<F>; 3]> {
+        self.0.iter().flat_map(Triangulate::triangulate).collect()
+    }
+}
+
+impl<F> Triangulate<F> for Polygon<F>
+where
+    F: Float,
+{
+    fn triangulate(&self) -> Vec<[Coordinate<F>; 3]> {
+        ear_clip(bridge_holes(self))
+    }
+}
+
+fn signed_area<F: Float>(ring: &[Coordinate<F>]) -> F {
+    let mut sum = F::zero();
+    for i in 0..ring.len() {
+        let a = ring[i];
+        let b = ring[(i + 1) % ring.len()];
+        sum = sum + (a.x * b.y - b.x * a.y);
+    }
+    sum
+}
+
+fn orientation<F: Float>(a: Coordinate<F>, b: Coordinate<F>, c: Coordinate<F>) -> F {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+fn ring_points<F: Float>(ring: &geo::LineString<F>) -> Vec<Coordinate<F>> {
+    let mut points = ring.0.clone();
+    if points.len() > 1 && points[0] == points[points.len() - 1] {
+        points.pop();
+    }
+    points
+}
+
+fn oriented<F: Float>(mut ring: Vec<Coordinate<F>>, ccw: bool) -> Vec<Coordinate<F>> {
+    let is_ccw = signed_area(&ring) > F::zero();
+    if is_ccw != ccw {
+        ring.reverse();
+    }
+    ring
+}
+
+/// Whether segments `a`->`b` and `c`->`d` are collinear and overlap along a sub-segment of
+/// positive length, e.g. `a`->`b` running along part of `c`->`d` rather than merely touching it
+/// at a single point. Used by `crosses_ring` to catch the case a proper-crossing test can't: a
+/// bridge that shares an endpoint with a ring edge but still runs along its length, which is just
+/// as invalid a bridge as one that crosses the edge outright.
+fn segments_collinear_overlap<F: Float>(a: Coordinate<F>, b: Coordinate<F>, c: Coordinate<F>, d: Coordinate<F>) -> bool {
+    if orientation(a, b, c) != F::zero() || orientation(a, b, d) != F::zero() {
+        return false;
+    }
+    let use_x = (b.x - a.x).abs() >= (b.y - a.y).abs();
+    let (lo_ab, hi_ab) = if use_x { (a.x.min(b.x), a.x.max(b.x)) } else { (a.y.min(b.y), a.y.max(b.y)) };
+    let (lo_cd, hi_cd) = if use_x { (c.x.min(d.x), c.x.max(d.x)) } else { (c.y.min(d.y), c.y.max(d.y)) };
+    lo_ab.max(lo_cd) < hi_ab.min(hi_cd)
+}
+
+/// Whether `a`->`b` crosses any edge of `ring`, either a proper transversal crossing or a
+/// collinear overlap, other than edges that merely touch `a` or `b` at a shared endpoint without
+/// also running along the same line.
+fn crosses_ring<F: Float>(a: Coordinate<F>, b: Coordinate<F>, ring: &[Coordinate<F>]) -> bool {
+    let n = ring.len();
+    for i in 0..n {
+        let c = ring[i];
+        let d = ring[(i + 1) % n];
+        let shares_endpoint = c == a || c == b || d == a || d == b;
+
+        if !shares_endpoint {
+            let o1 = orientation(a, b, c).signum();
+            let o2 = orientation(a, b, d).signum();
+            let o3 = orientation(c, d, a).signum();
+            let o4 = orientation(c, d, b).signum();
+            if o1 != o2 && o3 != o4 {
+                return true;
+            }
+        }
+
+        if segments_collinear_overlap(a, b, c, d) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Bridges every hole of `polygon` into its exterior ring, producing a single simple ring that
+/// can be fed directly to ear-clipping. Each hole's rightmost vertex is connected to the nearest
+/// vertex of the ring built so far that it has a clear line of sight to, where "clear" also
+/// accounts for holes that haven't been bridged in yet: a bridge is only taken if it crosses
+/// neither the ring built so far, the current hole, nor any later hole's boundary, so an earlier
+/// hole's bridge can't cut through one that's still waiting to be processed.
+fn bridge_holes<F>(polygon: &Polygon<F>) -> Vec<Coordinate<F>>
+where
+    F: Float,
+{
+    let mut vertices = oriented(ring_points(&polygon.exterior), true);
+
+    let holes: Vec<Vec<Coordinate<F>>> = polygon
+        .interiors
+        .iter()
+        .map(|interior| oriented(ring_points(interior), false))
+        .filter(|hole| hole.len() >= 3)
+        .collect();
+
+    for (hole_idx, hole) in holes.iter().enumerate() {
+        let not_yet_bridged = &holes[hole_idx + 1..];
+
+        let rightmost = hole
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.x.partial_cmp(&b.x).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        let m = hole[rightmost];
+
+        let mut bridge_idx = None;
+        let mut bridge_dist = F::infinity();
+        for (idx, &v) in vertices.iter().enumerate() {
+            if crosses_ring(v, m, &vertices)
+                || crosses_ring(v, m, hole)
+                || not_yet_bridged.iter().any(|other| crosses_ring(v, m, other))
+            {
+                continue;
+            }
+            let d = (v.x - m.x) * (v.x - m.x) + (v.y - m.y) * (v.y - m.y);
+            if d < bridge_dist {
+                bridge_dist = d;
+                bridge_idx = Some(idx);
+            }
+        }
+
+        // Fall back to the nearest vertex even if the naive line-of-sight test above rejected
+        // every candidate (e.g. a hole touching the exterior ring); a degenerate bridge edge is
+        // still preferable to silently dropping the hole from the triangulation.
+        let bridge_idx = bridge_idx.unwrap_or_else(|| {
+            vertices
+                .iter()
+                .enumerate()
+                .map(|(idx, v)| (idx, (v.x - m.x) * (v.x - m.x) + (v.y - m.y) * (v.y - m.y)))
+                .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(idx, _)| idx)
+                .unwrap_or(0)
+        });
+        let bridge_point = vertices[bridge_idx];
+
+        let mut splice = Vec::with_capacity(hole.len() + 2);
+        for i in 0..=hole.len() {
+            splice.push(hole[(rightmost + i) % hole.len()]);
+        }
+        splice.push(bridge_point);
+
+        vertices.splice(bridge_idx + 1..bridge_idx + 1, splice);
+    }
+
+    vertices
+}
+
+fn point_in_triangle<F: Float>(p: Coordinate<F>, a: Coordinate<F>, b: Coordinate<F>, c: Coordinate<F>) -> bool {
+    // `bridge_holes` deliberately duplicates the bridge vertex and the hole-closing vertex when
+    // it splices a hole into the ring, so `p` coinciding with one of the triangle's own corners
+    // is an everyday occurrence (not just malformed input) and must not veto the ear: it isn't
+    // a *different* point sitting inside the candidate triangle.
+    if p == a || p == b || p == c {
+        return false;
+    }
+
+    let d1 = orientation(p, a, b);
+    let d2 = orientation(p, b, c);
+    let d3 = orientation(p, c, a);
+
+    let has_neg = d1 < F::zero() || d2 < F::zero() || d3 < F::zero();
+    let has_pos = d1 > F::zero() || d2 > F::zero() || d3 > F::zero();
+
+    !(has_neg && has_pos)
+}
+
+fn is_ear<F: Float>(ring: &[Coordinate<F>], prev: usize, current: usize, next_idx: usize, next: &[usize]) -> bool {
+    let (a, b, c) = (ring[prev], ring[current], ring[next_idx]);
+
+    if orientation(a, b, c) <= F::zero() {
+        return false;
+    }
+
+    let mut j = next[next_idx];
+    while j != prev {
+        if point_in_triangle(ring[j], a, b, c) {
+            return false;
+        }
+        j = next[j];
+    }
+
+    true
+}
+
+/// Clips convex "ears" off `ring` (assumed already simple, with holes bridged in) until only a
+/// single triangle remains, using a doubly-linked index list so removed vertices are skipped in
+/// constant time.
+fn ear_clip<F>(ring: Vec<Coordinate<F>>) -> Vec<[Coordinate<F>; 3]>
+where
+    F: Float,
+{
+    let n = ring.len();
+    if n < 3 {
+        return Vec::new();
+    }
+
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+
+    let mut triangles = Vec::with_capacity(n - 2);
+    let mut remaining = n;
+    let mut current = 0;
+    // An ear-clipping pass over a simple polygon always removes a vertex within n attempts;
+    // bound the search generously to guard against a malformed (self-intersecting) input ring.
+    let mut attempts_since_progress = 0;
+
+    while remaining > 2 && attempts_since_progress <= remaining {
+        let p = prev[current];
+        let nx = next[current];
+
+        if is_ear(&ring, p, current, nx, &next) {
+            triangles.push([ring[p], ring[current], ring[nx]]);
+            next[p] = nx;
+            prev[nx] = p;
+            remaining -= 1;
+            current = p;
+            attempts_since_progress = 0;
+        } else {
+            current = nx;
+            attempts_since_progress += 1;
+        }
+    }
+
+    triangles
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::LineString;
+
+    fn ring(points: &[(f64, f64)]) -> LineString<f64> {
+        let mut coords: Vec<Coordinate<f64>> = points.iter().map(|&(x, y)| Coordinate { x, y }).collect();
+        coords.push(coords[0]);
+        LineString(coords)
+    }
+
+    fn triangle_area(t: &[Coordinate<f64>; 3]) -> f64 {
+        signed_area(t).abs() / 2.0
+    }
+
+    #[test]
+    fn triangulates_a_square_with_a_hole_covering_the_full_area() {
+        let square = Polygon::new(
+            ring(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]),
+            vec![ring(&[(2.0, 2.0), (4.0, 2.0), (4.0, 4.0), (2.0, 4.0)])],
+        );
+
+        let triangles = square.triangulate();
+
+        assert_eq!(triangles.len(), 8, "expected 8 triangles, got {}", triangles.len());
+        let total_area: f64 = triangles.iter().map(triangle_area).sum();
+        assert!(
+            (total_area - 96.0).abs() < 1e-9,
+            "expected total area 96, got {}",
+            total_area
+        );
+    }
+
+    #[test]
+    fn triangulates_a_square_with_a_hole_touching_the_exterior_boundary() {
+        // The hole's right edge (10,3)-(10,7) is flush against, and collinear with, the exterior
+        // ring's right edge (10,0)-(10,10), so the nearest bridge candidate for the hole's
+        // rightmost vertex runs along that edge rather than crossing it transversally. The four
+        // vertices left on x = 10 are collinear, so no convex ear remains among them: they make
+        // a zero-area sliver that is not emitted, and 6 triangles cover the whole area.
+        let square = Polygon::new(
+            ring(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]),
+            vec![ring(&[(6.0, 3.0), (10.0, 3.0), (10.0, 7.0), (6.0, 7.0)])],
+        );
+
+        let triangles = square.triangulate();
+
+        assert_eq!(triangles.len(), 6, "expected 6 triangles, got {}", triangles.len());
+        let total_area: f64 = triangles.iter().map(triangle_area).sum();
+        assert!(
+            (total_area - 84.0).abs() < 1e-9,
+            "expected total area 84, got {}",
+            total_area
+        );
+    }
+
+    #[test]
+    fn point_in_triangle_does_not_veto_its_own_corners() {
+        let (a, b, c) = (
+            Coordinate { x: 0.0, y: 0.0 },
+            Coordinate { x: 4.0, y: 0.0 },
+            Coordinate { x: 0.0, y: 4.0 },
+        );
+        assert!(!point_in_triangle(a, a, b, c));
+        assert!(!point_in_triangle(b, a, b, c));
+        assert!(!point_in_triangle(c, a, b, c));
+        assert!(point_in_triangle(Coordinate { x: 1.0, y: 1.0 }, a, b, c));
+    }
+}